@@ -0,0 +1,144 @@
+use core::fmt;
+
+use crate::fields::primitive::discriminant::UnknownDiscriminantError;
+use crate::fields::primitive::runtime_offset::SizeTooBigError;
+use crate::Field;
+
+/// Returned when a `NonZero*` field decodes to zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonZeroIsZeroError;
+
+impl fmt::Display for NonZeroIsZeroError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value is zero but field is declared NonZero")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NonZeroIsZeroError {}
+
+/// Error returned by a generated `View::validate()`, identifying the first field that failed
+/// validation and why.
+///
+/// `validate()` walks every field of the layout in declaration order and checks it the same way
+/// its individual accessor would (buffer length, `NonZero*` fields, discriminant fields, ...),
+/// modeled on the way rustc's interpreter `validity.rs` walks an aggregate value and reports the
+/// first invalid component it finds, rather than continuing to collect every error.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+/// use core::num::NonZeroU32;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   count: NonZeroU32,
+///   tail: [u8],
+/// });
+///
+/// fn func(storage_data: &[u8]) -> Result<(), ValidationError> {
+///   let view = my_layout::View::new(storage_data);
+///   view.validate()?;
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The storage is too short to even contain the named field at its offset.
+    FieldTooShort {
+        /// Name of the field that didn't fit.
+        field: &'static str,
+        /// Byte offset at which the field was expected to start.
+        offset: usize,
+        /// Size in bytes of the field.
+        size: usize,
+        /// Actual length of the storage that was validated.
+        storage_len: usize,
+    },
+    /// A `NonZero*` field held a zero value.
+    NonZeroIsZero {
+        /// Name of the field that held a zero value.
+        field: &'static str,
+    },
+    /// A discriminant (tag) field held a value with no matching `variants` arm.
+    UnknownDiscriminant(UnknownDiscriminantError),
+    /// A length-prefixed field declared a length that doesn't fit in the remaining storage, or a
+    /// fixed field following it doesn't fit.
+    SizeTooBig(SizeTooBigError),
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FieldTooShort {
+                field,
+                offset,
+                size,
+                storage_len,
+            } => write!(
+                f,
+                "field '{}' needs bytes [{}, {}) but storage is only {} bytes",
+                field,
+                offset,
+                offset + size,
+                storage_len,
+            ),
+            Self::NonZeroIsZero { field } => {
+                write!(f, "field '{}' is declared NonZero but holds a zero value", field)
+            }
+            Self::UnknownDiscriminant(err) => fmt::Display::fmt(err, f),
+            Self::SizeTooBig(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+impl From<UnknownDiscriminantError> for ValidationError {
+    fn from(err: UnknownDiscriminantError) -> Self {
+        Self::UnknownDiscriminant(err)
+    }
+}
+
+impl From<SizeTooBigError> for ValidationError {
+    fn from(err: SizeTooBigError) -> Self {
+        Self::SizeTooBig(err)
+    }
+}
+
+/// Implemented by every field kind that a generated `View::validate()` can check in one pass over
+/// the whole buffer: it re-validates the field the same way its `try_read`/read accessor would,
+/// discarding any decoded [`HighLevelType`](crate::Field::HighLevelType) instead of returning it.
+///
+/// `define_layout!` generates a `validate()` method on `View` that calls this for every field with
+/// a generated marker struct in declaration order (nested-layout and length-prefixed fields, and
+/// the fields after them, are checked inline instead) and returns on the first error, rather than
+/// collecting all of them. The
+/// default [`validate`](FieldValidator::validate) checks that the field's declared byte range
+/// fits in `storage` and then defers to [`validate_value`](FieldValidator::validate_value) for
+/// anything kind-specific (e.g. rejecting a zero `NonZero*` value); plain fields that have no
+/// extra invariants just use the default `Ok(())` body.
+pub trait FieldValidator: Field {
+    /// Name of this field, used to build a [`ValidationError`] that names the culprit.
+    const NAME: &'static str;
+
+    /// Kind-specific validation beyond "does it fit". `storage` covers the whole layout.
+    #[inline(always)]
+    fn validate_value(_storage: &[u8]) -> Result<(), ValidationError> {
+        Ok(())
+    }
+
+    /// Check that `storage` holds a valid instance of this field.
+    #[inline(always)]
+    fn validate(storage: &[u8]) -> Result<(), ValidationError> {
+        if Self::OFFSET + Self::SIZE > storage.len() {
+            return Err(ValidationError::FieldTooShort {
+                field: Self::NAME,
+                offset: Self::OFFSET,
+                size: Self::SIZE,
+                storage_len: storage.len(),
+            });
+        }
+        Self::validate_value(storage)
+    }
+}
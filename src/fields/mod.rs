@@ -0,0 +1,53 @@
+//! Traits implemented by the zero-sized field marker types that
+//! [`define_layout!`](crate::define_layout!) generates, plus the primitive field kinds built on
+//! top of them.
+
+pub mod primitive;
+
+/// Marker for little-endian byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LittleEndian;
+
+/// Marker for big-endian byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BigEndian;
+
+/// Field metadata: which bytes a field occupies within its layout and what Rust type it decodes
+/// to. Implemented by the marker types [`define_layout!`](crate::define_layout!) generates, never
+/// by hand.
+pub trait Field {
+    /// The decoded Rust type this field reads as / writes from.
+    type HighLevelType;
+
+    /// Byte offset at which this field starts within its layout.
+    const OFFSET: usize;
+
+    /// Size in bytes of this field.
+    const SIZE: usize;
+}
+
+/// A [Field] that can be read without failing.
+pub trait FieldReadExt: Field {
+    /// Read the field out of `storage`, which covers the whole layout (not just this field).
+    fn read(storage: &[u8]) -> Self::HighLevelType;
+}
+
+/// A [Field] that can be written without failing.
+pub trait FieldWriteExt: Field {
+    /// Write the field into `storage`, which covers the whole layout (not just this field).
+    fn write(storage: &mut [u8], v: Self::HighLevelType);
+}
+
+/// A [Field] whose read/write can fail, e.g. a `NonZero*` field encountering a zero value in the
+/// buffer.
+pub trait FieldCopyAccess: Field {
+    /// Error returned by [`try_read`](FieldCopyAccess::try_read).
+    type ReadError;
+    /// Error returned by [`try_write`](FieldCopyAccess::try_write).
+    type WriteError;
+
+    /// Read the field out of `storage`, failing if the bytes don't hold a valid value.
+    fn try_read(storage: &[u8]) -> Result<Self::HighLevelType, Self::ReadError>;
+    /// Write the field into `storage`, failing if `v` can't be represented.
+    fn try_write(storage: &mut [u8], v: Self::HighLevelType) -> Result<(), Self::WriteError>;
+}
@@ -0,0 +1,47 @@
+use core::fmt;
+
+/// Returned when a discriminant (tag) field holds a value that doesn't match any of the
+/// `variants { ... }` arms declared for it in a [`define_layout!`](crate::define_layout!) block:
+/// there's no sub-layout to project the remaining bytes into.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   tag: u8,
+///   variants {
+///     0 => A { value: u32, },
+///     1 => B { value: u8, },
+///   }
+/// });
+///
+/// fn func(storage_data: &[u8]) -> Result<(), UnknownDiscriminantError> {
+///   let view = my_layout::View::new(storage_data);
+///   match view.variant()? {
+///     my_layout::Variant::A(a) => { a.value().read(); }
+///     my_layout::Variant::B(b) => { b.value().read(); }
+///   }
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownDiscriminantError {
+    /// Name of the tag field that was read.
+    pub field: &'static str,
+    /// The tag value that was found in the buffer.
+    pub discriminant: u64,
+}
+
+impl fmt::Display for UnknownDiscriminantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "discriminant field '{}' has value {} which doesn't match any declared variant",
+            self.field, self.discriminant,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownDiscriminantError {}
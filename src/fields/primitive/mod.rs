@@ -0,0 +1,287 @@
+//! Primitive (fixed-size integer and `NonZero*`) field kinds, plus the view types and helpers
+//! built on top of them.
+//!
+//! [`define_layout!`](crate::define_layout!) doesn't reuse one generic field type for every
+//! integer field; it generates one zero-sized marker struct per declared field name and calls
+//! [`_impl_primitive_field!`] / [`_impl_nonzero_field!`] to implement [Field] and friends for it
+//! directly, with that field's own `OFFSET`/`NAME` baked in as associated consts. This keeps every
+//! field's offset a real compile-time constant even though different fields of the same type live
+//! at different offsets, and gives [`crate::errors::FieldValidator`] a field name to report without
+//! needing string-valued const generics (which stable Rust doesn't support).
+
+pub mod discriminant;
+pub mod nested;
+pub mod runtime_offset;
+pub mod view;
+
+/// An integer primitive a field can store directly (as opposed to through a `NonZero*` wrapper).
+pub trait Primitive: Copy {
+    /// Size in bytes of the encoded value.
+    const SIZE: usize;
+    fn from_le(bytes: &[u8]) -> Self;
+    fn from_be(bytes: &[u8]) -> Self;
+    fn write_le(self, bytes: &mut [u8]);
+    fn write_be(self, bytes: &mut [u8]);
+}
+
+macro_rules! impl_primitive {
+    ($t:ty) => {
+        impl Primitive for $t {
+            const SIZE: usize = core::mem::size_of::<$t>();
+
+            #[inline(always)]
+            fn from_le(bytes: &[u8]) -> Self {
+                <$t>::from_le_bytes(bytes.try_into().unwrap())
+            }
+            #[inline(always)]
+            fn from_be(bytes: &[u8]) -> Self {
+                <$t>::from_be_bytes(bytes.try_into().unwrap())
+            }
+            #[inline(always)]
+            fn write_le(self, bytes: &mut [u8]) {
+                bytes.copy_from_slice(&self.to_le_bytes());
+            }
+            #[inline(always)]
+            fn write_be(self, bytes: &mut [u8]) {
+                bytes.copy_from_slice(&self.to_be_bytes());
+            }
+        }
+    };
+}
+
+impl_primitive!(u8);
+impl_primitive!(i8);
+impl_primitive!(u16);
+impl_primitive!(i16);
+impl_primitive!(u32);
+impl_primitive!(i32);
+impl_primitive!(u64);
+impl_primitive!(i64);
+
+/// A `NonZero*` integer a field can store, encoded the same way as its zero-admitting
+/// [`Primitive`] counterpart (its [`Repr`](PrimitiveNonZero::Repr)) but rejected on read/write if
+/// it decodes to zero.
+pub trait PrimitiveNonZero: Copy {
+    /// The all-values-allowed integer type this is encoded as.
+    type Repr: Primitive;
+    fn new(repr: Self::Repr) -> Option<Self>;
+    fn get(self) -> Self::Repr;
+}
+
+macro_rules! impl_primitive_non_zero {
+    ($nz:ty, $repr:ty) => {
+        impl PrimitiveNonZero for $nz {
+            type Repr = $repr;
+            #[inline(always)]
+            fn new(repr: $repr) -> Option<Self> {
+                <$nz>::new(repr)
+            }
+            #[inline(always)]
+            fn get(self) -> $repr {
+                <$nz>::get(self)
+            }
+        }
+    };
+}
+
+impl_primitive_non_zero!(core::num::NonZeroU8, u8);
+impl_primitive_non_zero!(core::num::NonZeroI8, i8);
+impl_primitive_non_zero!(core::num::NonZeroU16, u16);
+impl_primitive_non_zero!(core::num::NonZeroI16, i16);
+impl_primitive_non_zero!(core::num::NonZeroU32, u32);
+impl_primitive_non_zero!(core::num::NonZeroI32, i32);
+impl_primitive_non_zero!(core::num::NonZeroU64, u64);
+impl_primitive_non_zero!(core::num::NonZeroI64, i64);
+
+/// Picks one of two token trees based on a literal `LittleEndian`/`BigEndian` ident. Lets the
+/// per-field impl macros below stay endian-generic instead of needing a `_le`/`_be` twin each.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _dl_pick_endian {
+    (LittleEndian, $le:expr, $be:expr) => {
+        $le
+    };
+    (BigEndian, $le:expr, $be:expr) => {
+        $be
+    };
+}
+
+/// Implement [`Field`](crate::fields::Field),
+/// [`FieldReadExt`](crate::fields::FieldReadExt), [`FieldWriteExt`](crate::fields::FieldWriteExt)
+/// and [`FieldValidator`](crate::errors::FieldValidator) for a zero-sized marker struct
+/// `define_layout!` has already declared for one plain integer field.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _impl_primitive_field {
+    ($marker:ident, $t:ty, $endian:ident, $name:expr, $offset:expr) => {
+        impl $crate::fields::Field for $marker {
+            type HighLevelType = $t;
+            const OFFSET: usize = $offset;
+            const SIZE: usize = <$t as $crate::fields::primitive::Primitive>::SIZE;
+        }
+        impl $crate::fields::FieldReadExt for $marker {
+            #[inline(always)]
+            fn read(storage: &[u8]) -> $t {
+                let bytes = &storage[$offset..$offset + <$t as $crate::fields::primitive::Primitive>::SIZE];
+                $crate::_dl_pick_endian!(
+                    $endian,
+                    <$t as $crate::fields::primitive::Primitive>::from_le(bytes),
+                    <$t as $crate::fields::primitive::Primitive>::from_be(bytes)
+                )
+            }
+        }
+        impl $crate::fields::FieldWriteExt for $marker {
+            #[inline(always)]
+            fn write(storage: &mut [u8], v: $t) {
+                let bytes = &mut storage[$offset..$offset + <$t as $crate::fields::primitive::Primitive>::SIZE];
+                $crate::_dl_pick_endian!(
+                    $endian,
+                    <$t as $crate::fields::primitive::Primitive>::write_le(v, bytes),
+                    <$t as $crate::fields::primitive::Primitive>::write_be(v, bytes)
+                )
+            }
+        }
+        impl $crate::errors::FieldValidator for $marker {
+            const NAME: &'static str = $name;
+        }
+    };
+}
+
+/// Implement [`Field`], [`FieldCopyAccess`](crate::fields::FieldCopyAccess) and
+/// [`FieldValidator`](crate::errors::FieldValidator) for a zero-sized marker struct
+/// `define_layout!` has already declared for one `NonZero*` field.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _impl_nonzero_field {
+    ($marker:ident, $t:ty, $endian:ident, $name:expr, $offset:expr) => {
+        impl $crate::fields::Field for $marker {
+            type HighLevelType = $t;
+            const OFFSET: usize = $offset;
+            const SIZE: usize =
+                <<$t as $crate::fields::primitive::PrimitiveNonZero>::Repr as $crate::fields::primitive::Primitive>::SIZE;
+        }
+        impl $crate::fields::FieldCopyAccess for $marker {
+            type ReadError = $crate::errors::NonZeroIsZeroError;
+            type WriteError = core::convert::Infallible;
+
+            #[inline(always)]
+            fn try_read(storage: &[u8]) -> Result<$t, $crate::errors::NonZeroIsZeroError> {
+                let bytes = &storage[$offset..$offset + <Self as $crate::Field>::SIZE];
+                let repr = $crate::_dl_pick_endian!(
+                    $endian,
+                    <<$t as $crate::fields::primitive::PrimitiveNonZero>::Repr as $crate::fields::primitive::Primitive>::from_le(bytes),
+                    <<$t as $crate::fields::primitive::PrimitiveNonZero>::Repr as $crate::fields::primitive::Primitive>::from_be(bytes)
+                );
+                <$t as $crate::fields::primitive::PrimitiveNonZero>::new(repr).ok_or($crate::errors::NonZeroIsZeroError)
+            }
+            #[inline(always)]
+            fn try_write(storage: &mut [u8], v: $t) -> Result<(), core::convert::Infallible> {
+                let repr = <$t as $crate::fields::primitive::PrimitiveNonZero>::get(v);
+                let bytes = &mut storage[$offset..$offset + <Self as $crate::Field>::SIZE];
+                $crate::_dl_pick_endian!(
+                    $endian,
+                    <<$t as $crate::fields::primitive::PrimitiveNonZero>::Repr as $crate::fields::primitive::Primitive>::write_le(repr, bytes),
+                    <<$t as $crate::fields::primitive::PrimitiveNonZero>::Repr as $crate::fields::primitive::Primitive>::write_be(repr, bytes)
+                );
+                Ok(())
+            }
+        }
+        impl $crate::errors::FieldValidator for $marker {
+            const NAME: &'static str = $name;
+
+            #[inline(always)]
+            fn validate_value(storage: &[u8]) -> Result<(), $crate::errors::ValidationError> {
+                use $crate::fields::FieldCopyAccess;
+                <Self as FieldCopyAccess>::try_read(storage)
+                    .map(|_| ())
+                    .map_err(|_| $crate::errors::ValidationError::NonZeroIsZero { field: $name })
+            }
+        }
+    };
+}
+
+/// Dispatches a bare primitive/`NonZero*` type ident to [`_impl_primitive_field!`] or
+/// [`_impl_nonzero_field!`]. The type ident was captured by `_dl_fields!` as an `ident` fragment,
+/// which (unlike `ty`/`path`/`expr`) stable `macro_rules!` still lets a later macro re-match
+/// against a literal token -- that's why field types are written as bare idents here (`u8`,
+/// `NonZeroU32`, ...) rather than full paths like `core::num::NonZeroU32`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _dl_field_dispatch {
+    ($endian:ident, u8, $marker:ident, $name:expr, $offset:expr) => {
+        $crate::_impl_primitive_field!($marker, u8, $endian, $name, $offset);
+    };
+    ($endian:ident, i8, $marker:ident, $name:expr, $offset:expr) => {
+        $crate::_impl_primitive_field!($marker, i8, $endian, $name, $offset);
+    };
+    ($endian:ident, u16, $marker:ident, $name:expr, $offset:expr) => {
+        $crate::_impl_primitive_field!($marker, u16, $endian, $name, $offset);
+    };
+    ($endian:ident, i16, $marker:ident, $name:expr, $offset:expr) => {
+        $crate::_impl_primitive_field!($marker, i16, $endian, $name, $offset);
+    };
+    ($endian:ident, u32, $marker:ident, $name:expr, $offset:expr) => {
+        $crate::_impl_primitive_field!($marker, u32, $endian, $name, $offset);
+    };
+    ($endian:ident, i32, $marker:ident, $name:expr, $offset:expr) => {
+        $crate::_impl_primitive_field!($marker, i32, $endian, $name, $offset);
+    };
+    ($endian:ident, u64, $marker:ident, $name:expr, $offset:expr) => {
+        $crate::_impl_primitive_field!($marker, u64, $endian, $name, $offset);
+    };
+    ($endian:ident, i64, $marker:ident, $name:expr, $offset:expr) => {
+        $crate::_impl_primitive_field!($marker, i64, $endian, $name, $offset);
+    };
+    ($endian:ident, NonZeroU8, $marker:ident, $name:expr, $offset:expr) => {
+        $crate::_impl_nonzero_field!($marker, core::num::NonZeroU8, $endian, $name, $offset);
+    };
+    ($endian:ident, NonZeroI8, $marker:ident, $name:expr, $offset:expr) => {
+        $crate::_impl_nonzero_field!($marker, core::num::NonZeroI8, $endian, $name, $offset);
+    };
+    ($endian:ident, NonZeroU16, $marker:ident, $name:expr, $offset:expr) => {
+        $crate::_impl_nonzero_field!($marker, core::num::NonZeroU16, $endian, $name, $offset);
+    };
+    ($endian:ident, NonZeroI16, $marker:ident, $name:expr, $offset:expr) => {
+        $crate::_impl_nonzero_field!($marker, core::num::NonZeroI16, $endian, $name, $offset);
+    };
+    ($endian:ident, NonZeroU32, $marker:ident, $name:expr, $offset:expr) => {
+        $crate::_impl_nonzero_field!($marker, core::num::NonZeroU32, $endian, $name, $offset);
+    };
+    ($endian:ident, NonZeroI32, $marker:ident, $name:expr, $offset:expr) => {
+        $crate::_impl_nonzero_field!($marker, core::num::NonZeroI32, $endian, $name, $offset);
+    };
+    ($endian:ident, NonZeroU64, $marker:ident, $name:expr, $offset:expr) => {
+        $crate::_impl_nonzero_field!($marker, core::num::NonZeroU64, $endian, $name, $offset);
+    };
+    ($endian:ident, NonZeroI64, $marker:ident, $name:expr, $offset:expr) => {
+        $crate::_impl_nonzero_field!($marker, core::num::NonZeroI64, $endian, $name, $offset);
+    };
+}
+
+/// Read a bare primitive type ident's value out of `$bytes`, endian-aware. Used for the fixed
+/// fields that follow a length-prefixed field, which (per [`runtime_offset`]) can't go through
+/// [`Field`](crate::fields::Field)'s compile-time `OFFSET` and so don't get a marker struct.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _dl_runtime_read {
+    ($endian:ident, $t:ty, $bytes:expr) => {
+        $crate::_dl_pick_endian!(
+            $endian,
+            <$t as $crate::fields::primitive::Primitive>::from_le($bytes),
+            <$t as $crate::fields::primitive::Primitive>::from_be($bytes)
+        )
+    };
+}
+
+/// Write-side twin of [`_dl_runtime_read!`].
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _dl_runtime_write {
+    ($endian:ident, $t:ty, $v:expr, $bytes:expr) => {
+        $crate::_dl_pick_endian!(
+            $endian,
+            <$t as $crate::fields::primitive::Primitive>::write_le($v, $bytes),
+            <$t as $crate::fields::primitive::Primitive>::write_be($v, $bytes)
+        )
+    };
+}
@@ -0,0 +1,51 @@
+/// Borrow the byte range `[start, start+len)` of `storage`, panicking with a clear message that
+/// names the nested field if the storage is too short.
+///
+/// This is the runtime helper [`define_layout!`](crate::define_layout!) emits a call to for every
+/// field whose bytes are another layout (declared as e.g. `header: my_header::NestedView`),
+/// so that every such nested-layout accessor shares one panic message instead of each call site
+/// formatting its own.
+#[inline]
+pub fn nested_subslice<'a>(
+    storage: &'a [u8],
+    field_name: &'static str,
+    start: usize,
+    len: usize,
+) -> &'a [u8] {
+    start
+        .checked_add(len)
+        .and_then(|end| storage.get(start..end))
+        .unwrap_or_else(|| {
+            panic!(
+                "Tried to view nested field '{}' at bytes [{}, {}) but storage is only {} bytes",
+                field_name,
+                start,
+                start.saturating_add(len),
+                storage.len(),
+            )
+        })
+}
+
+/// Mutable counterpart of [`nested_subslice`], used for the generated `_mut` accessor of a nested
+/// field (e.g. `view.header_mut()`).
+#[inline]
+pub fn nested_subslice_mut<'a>(
+    storage: &'a mut [u8],
+    field_name: &'static str,
+    start: usize,
+    len: usize,
+) -> &'a mut [u8] {
+    let storage_len = storage.len();
+    start
+        .checked_add(len)
+        .and_then(move |end| storage.get_mut(start..end))
+        .unwrap_or_else(|| {
+            panic!(
+                "Tried to view nested field '{}' at bytes [{}, {}) but storage is only {} bytes",
+                field_name,
+                start,
+                start.saturating_add(len),
+                storage_len,
+            )
+        })
+}
@@ -0,0 +1,72 @@
+use core::fmt;
+
+/// Returned when a length-prefixed field (e.g. `payload: [u8; len]`) declares a length that
+/// doesn't fit in the remaining storage, or when a fixed field following it doesn't fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeTooBigError {
+    /// Name of the field that didn't fit.
+    pub field: &'static str,
+    /// The length read from the preceding length field, or the fixed size of a field following
+    /// the length-prefixed one.
+    pub declared_len: usize,
+    /// The number of bytes actually available for this field.
+    pub available_len: usize,
+}
+
+impl fmt::Display for SizeTooBigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "field '{}' declares a length of {} bytes but only {} bytes are available",
+            self.field, self.declared_len, self.available_len,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SizeTooBigError {}
+
+/// Compute the runtime start offset of a field that follows a variable-length (length-prefixed)
+/// field, since such a field can no longer have its offset baked in as a compile-time constant.
+///
+/// `fixed_prefix_len` is the combined size of all fixed-size fields before the variable-length
+/// field, i.e. the offset at which it starts; `preceding_field_len` is the length that was read out
+/// of its length field. The result is the byte offset just past the variable-length field, i.e.
+/// where whatever follows it starts.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   len: u16,
+///   payload: [u8; len],
+///   checksum: u32,
+/// });
+///
+/// fn func(storage_data: &[u8]) -> Result<u32, SizeTooBigError> {
+///   let view = my_layout::View::new(storage_data);
+///   let payload_len = view.len().read() as usize;
+///   let payload: &[u8] = view.payload()?;
+///   assert_eq!(payload.len(), payload_len);
+///   view.checksum()
+/// }
+/// ```
+#[inline]
+pub fn runtime_offset_after_variable_field(
+    storage_len: usize,
+    fixed_prefix_len: usize,
+    preceding_field_name: &'static str,
+    preceding_field_len: usize,
+) -> Result<usize, SizeTooBigError> {
+    let end = fixed_prefix_len + preceding_field_len;
+    if end > storage_len {
+        Err(SizeTooBigError {
+            field: preceding_field_name,
+            declared_len: preceding_field_len,
+            available_len: storage_len.saturating_sub(fixed_prefix_len),
+        })
+    } else {
+        Ok(end)
+    }
+}
@@ -68,7 +68,7 @@ impl<S: AsRef<[u8]>, F: FieldReadExt> FieldView<S, F> {
     ///
     /// define_layout!(my_layout, LittleEndian, {
     ///   //... other fields ...
-    ///   some_integer_field: i8
+    ///   some_integer_field: i8,
     ///   //... other fields ...
     /// });
     ///
@@ -91,7 +91,7 @@ impl<S: AsMut<[u8]>, F: FieldWriteExt> FieldView<S, F> {
     ///
     /// define_layout!(my_layout, LittleEndian, {
     ///   //... other fields ...
-    ///   some_integer_field: i8
+    ///   some_integer_field: i8,
     ///   //... other fields ...
     /// });
     ///
@@ -115,7 +115,7 @@ impl<S: AsRef<[u8]>, F: FieldCopyAccess> FieldView<S, F> {
     ///
     /// define_layout!(my_layout, LittleEndian, {
     ///   //... other fields ...
-    ///   some_integer_field: core::num::NonZeroI8,
+    ///   some_integer_field: NonZeroI8,
     ///   //... other fields ...
     /// });
     ///
@@ -130,6 +130,34 @@ impl<S: AsRef<[u8]>, F: FieldCopyAccess> FieldView<S, F> {
         F::try_read(self.storage.as_ref())
     }
 }
+impl<S: AsMut<[u8]>, F: Field> FieldView<S, F> {
+    /// Copy this field's raw, still-encoded bytes from another view of the *same* field, without
+    /// decoding to [`HighLevelType`](Field::HighLevelType) and re-encoding it. Cheaper than
+    /// `other.read()` followed by `self.write(...)` for fields like fixed-size blobs that aren't
+    /// otherwise touched.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///   //... other fields ...
+    ///   blob: [u8; 16],
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(src: &[u8], dst: &mut [u8]) {
+    ///   let src_view = my_layout::View::new(src);
+    ///   let mut dst_view = my_layout::View::new(dst);
+    ///   dst_view.blob_mut().copy_from(&src_view.blob());
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn copy_from<S2: AsRef<[u8]>>(&mut self, other: &FieldView<S2, F>) {
+        let src = &other.storage.as_ref()[F::OFFSET..F::OFFSET + F::SIZE];
+        self.storage.as_mut()[F::OFFSET..F::OFFSET + F::SIZE].copy_from_slice(src);
+    }
+}
 impl<S: AsMut<[u8]>, F: FieldCopyAccess> FieldView<S, F> {
     /// Write the field to a given data region, assuming the defined layout, using the [FieldView] API.
     ///
@@ -141,7 +169,7 @@ impl<S: AsMut<[u8]>, F: FieldCopyAccess> FieldView<S, F> {
     ///
     /// define_layout!(my_layout, LittleEndian, {
     ///   //... other fields ...
-    ///   some_integer_field: core::num::NonZeroI8,
+    ///   some_integer_field: NonZeroI8,
     ///   //... other fields ...
     /// });
     ///
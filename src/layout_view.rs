@@ -0,0 +1,55 @@
+/// Implemented by the `View` struct [`define_layout!`](crate::define_layout!) generates for every
+/// fixed-size layout, so that a view over one layout can be reinterpreted as a view over another
+/// layout occupying the exact same storage. Layouts with an open-ended `[u8]` tail, a `variants`
+/// block or a length-prefixed field don't implement it.
+pub trait LayoutView<S>: Sized {
+    /// Total size in bytes of this layout, or `None` if its size isn't fixed. Every generated
+    /// implementation reports `Some`.
+    const SIZE: Option<usize>;
+
+    /// Wrap `storage` in this layout's view, the same way its generated `View::new` does.
+    fn from_storage(storage: S) -> Self;
+
+    /// Unwrap this view back into its underlying storage.
+    fn into_storage(self) -> S;
+}
+
+/// Reinterpret `view`'s storage as a view of a different layout. No bytes move: the same storage
+/// is simply viewed under `To`'s schema instead of `From`'s.
+///
+/// Every fixed-size layout's generated `View` has an inherent `view.reinterpret::<OtherLayout>()`
+/// method that just forwards here; call that instead of this function directly unless you're
+/// writing generic code over `LayoutView`.
+///
+/// # Panics
+/// Panics if both layouts have a fixed size and those sizes differ.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(generic_message, LittleEndian, { kind: u32, payload: u32, });
+/// define_layout!(ping_message, LittleEndian, { kind: u32, sequence_number: u32, });
+///
+/// fn func(storage_data: &[u8]) {
+///   let generic_view = generic_message::View::new(storage_data);
+///   if generic_view.kind().read() == 1 {
+///     let ping_view: ping_message::View<&[u8]> = generic_view.reinterpret();
+///     let _sequence_number = ping_view.sequence_number().read();
+///   }
+/// }
+/// ```
+pub fn reinterpret<S, From, To>(view: From) -> To
+where
+    From: LayoutView<S>,
+    To: LayoutView<S>,
+{
+    if let (Some(from_size), Some(to_size)) = (From::SIZE, To::SIZE) {
+        assert_eq!(
+            from_size, to_size,
+            "Cannot reinterpret a layout of size {} as a layout of size {}",
+            from_size, to_size,
+        );
+    }
+    To::from_storage(view.into_storage())
+}
@@ -0,0 +1,14 @@
+//! A library for zero-copy, panic-free parsing and writing of binary data that follows a fixed
+//! or (partially) runtime-defined layout.
+
+pub mod errors;
+pub mod fields;
+pub mod layout_view;
+#[macro_use]
+mod macros;
+pub mod prelude;
+
+#[doc(hidden)]
+pub use paste;
+
+pub use crate::fields::{BigEndian, Field, FieldCopyAccess, FieldReadExt, FieldWriteExt, LittleEndian};
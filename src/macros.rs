@@ -0,0 +1,620 @@
+//! The `define_layout!` macro and the token-munching machinery behind it.
+//!
+//! `define_layout!` walks its field list left to right, accumulating: the generated field marker
+//! structs and their trait impls (`@items`), the read-only accessor methods (`@ro`, go in an
+//! `impl<S: AsRef<[u8]>> View<S>` block), the mutable accessor methods (`@rw`, go in an
+//! `impl<S: AsMut<[u8]>> View<S>` block), the per-field validation statements `View::validate()`
+//! runs in order (`@validations`), the running compile-time byte offset (`@offset`), and the ident
+//! `@sv` that every validation statement reads the whole buffer through. Every arm below re-threads
+//! the same groups through the recursive call so the accumulator shape never changes mid-walk.
+//!
+//! `@sv` needs calling out: it's always spelled `storage`, but it's threaded through as a captured
+//! `ident` fragment (forwarded as `$sv`, never re-written as the literal word `storage`) so that
+//! every validation statement -- accumulated at a different recursive call than the one that
+//! finally binds `let storage = ...;` in a terminal arm -- refers to the *same* hygienic binding.
+//! `macro_rules!` hygiene treats an identifier written literally in two different macro expansions
+//! as two different bindings even if they're spelled the same way; forwarding the fragment instead
+//! of re-typing it is what keeps them the same binding across the whole walk.
+//!
+//! Supported field syntax:
+//! - `name: u8` / `i16` / ... / `i64`, or `name: NonZeroU8` / ... / `NonZeroI64` -- a fixed-size
+//!   integer field (see [`fields::primitive`](crate::fields::primitive)).
+//! - `name: [u8; N]` (`N` an integer literal) -- a fixed-size raw byte array.
+//! - `name: [u8]` -- an open-ended tail field; must be the last field.
+//! - `name: modname::NestedView` -- a field whose bytes are themselves another layout; see
+//!   [`fields::primitive::nested`](crate::fields::primitive::nested). `modname` must be a sibling
+//!   module holding a `define_layout!`-generated `View` with a module-level `SIZE` constant, i.e. a
+//!   fixed-size (non-tail, non-variable-length) layout.
+//! - `tag: u8, variants { DISCRIMINANT => VariantName { ...fields... }, ... }` -- a discriminated
+//!   union; must be the last two items. See [`fields::primitive::discriminant`].
+//! - `len: u16, payload: [u8; len], ...more fixed fields...` -- a length-prefixed field whose size
+//!   is read out of a preceding integer field at runtime; see
+//!   [`fields::primitive::runtime_offset`]. Once a layout enters this mode every further field's
+//!   offset is computed at runtime instead of getting a `Field` marker, so the layout can no longer
+//!   participate in [`reinterpret`](crate::layout_view::reinterpret) (its size isn't known at
+//!   compile time) and `copy_from` doesn't apply to it either. This mode doesn't currently support
+//!   a further nested/variant/tail field after it -- just more fixed integer fields.
+#[macro_export]
+macro_rules! define_layout {
+    ($name:ident, $endian:ident, { $($body:tt)* }) => {
+        pub mod $name {
+            #![allow(non_camel_case_types, non_snake_case, dead_code, unused_imports)]
+            use super::*;
+
+            $crate::_dl_fields!(
+                @endian[$endian]
+                @offset[0usize]
+                @items[]
+                @ro[]
+                @rw[]
+                @validations[]
+                @sv[storage]
+                @body[$($body)*]
+            );
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _dl_fields {
+    // ---- terminal: tag field immediately followed by a `variants { ... }` block ----
+    (@endian[$endian:ident] @offset[$offset:expr]
+     @items[$($items:item)*] @ro[$($ro:item)*] @rw[$($rw:item)*] @validations[$($validations:tt)*] @sv[$sv:ident]
+     @body[$tag:ident : $tagty:ident , variants { $($disc:literal => $vname:ident { $($vfields:tt)* }),+ $(,)? }]) => {
+        pub struct $tag;
+        $($items)*
+        $crate::_dl_field_dispatch!($endian, $tagty, $tag, stringify!($tag), $offset);
+
+        $crate::paste::paste! {
+            $crate::_dl_emit_variants!(
+                @endian[$endian]
+                @tag[$tag]
+                @tagty[$tagty]
+                @variant_offset[($offset) + (<$tag as $crate::Field>::SIZE)]
+                @ro[$($ro)*
+                    #[inline(always)]
+                    pub fn $tag(&self) -> $crate::fields::primitive::view::FieldView<&[u8], $tag> {
+                        $crate::fields::primitive::view::FieldView::new(self.storage.as_ref())
+                    }
+                ]
+                @rw[$($rw)*
+                    #[inline(always)]
+                    pub fn [<$tag _mut>](&mut self) -> $crate::fields::primitive::view::FieldView<&mut [u8], $tag> {
+                        $crate::fields::primitive::view::FieldView::new(self.storage.as_mut())
+                    }
+                ]
+                @validations[$($validations)* { <$tag as $crate::errors::FieldValidator>::validate($sv)?; }]
+                @sv[$sv]
+                @arms[$($disc => $vname { $($vfields)* })+]
+            );
+        }
+    };
+
+    // ---- terminal: open-ended tail field, must be last ----
+    (@endian[$endian:ident] @offset[$offset:expr]
+     @items[$($items:item)*] @ro[$($ro:item)*] @rw[$($rw:item)*] @validations[$($validations:tt)*] @sv[$sv:ident]
+     @body[$tail:ident : [u8] $(,)?]) => {
+        pub struct View<S> {
+            storage: S,
+        }
+        impl<S> View<S> {
+            #[inline(always)]
+            pub fn new(storage: S) -> Self {
+                Self { storage }
+            }
+            #[inline(always)]
+            pub fn into_storage(self) -> S {
+                self.storage
+            }
+        }
+        $($items)*
+        impl<S: AsRef<[u8]>> View<S> {
+            $($ro)*
+
+            /// Borrow everything from this field's offset to the end of the storage.
+            #[inline(always)]
+            pub fn $tail(&self) -> &[u8] {
+                &self.storage.as_ref()[$offset..]
+            }
+
+            /// Run every fixed field's validator, then require the storage to at least reach
+            /// where this tail field starts.
+            pub fn validate(&self) -> Result<(), $crate::errors::ValidationError> {
+                let $sv = self.storage.as_ref();
+                $($validations)*
+                if $sv.len() < $offset {
+                    return Err($crate::errors::ValidationError::FieldTooShort {
+                        field: stringify!($tail),
+                        offset: $offset,
+                        size: 0,
+                        storage_len: $sv.len(),
+                    });
+                }
+                Ok(())
+            }
+        }
+        $crate::paste::paste! {
+            impl<S: AsMut<[u8]>> View<S> {
+                $($rw)*
+
+                /// Mutably borrow everything from this field's offset to the end of the storage.
+                #[inline(always)]
+                pub fn [<$tail _mut>](&mut self) -> &mut [u8] {
+                    &mut self.storage.as_mut()[$offset..]
+                }
+            }
+        }
+    };
+
+    // ---- terminal: flat, fully fixed-size layout ----
+    (@endian[$endian:ident] @offset[$offset:expr]
+     @items[$($items:item)*] @ro[$($ro:item)*] @rw[$($rw:item)*] @validations[$($validations:tt)*] @sv[$sv:ident]
+     @body[]) => {
+        pub struct View<S> {
+            storage: S,
+        }
+        impl<S> View<S> {
+            #[inline(always)]
+            pub fn new(storage: S) -> Self {
+                Self { storage }
+            }
+            #[inline(always)]
+            pub fn into_storage(self) -> S {
+                self.storage
+            }
+        }
+        /// Total size in bytes of this layout.
+        pub const SIZE: usize = $offset;
+        $($items)*
+        impl<S> $crate::layout_view::LayoutView<S> for View<S> {
+            const SIZE: Option<usize> = Some(SIZE);
+            #[inline(always)]
+            fn from_storage(storage: S) -> Self {
+                Self::new(storage)
+            }
+            #[inline(always)]
+            fn into_storage(self) -> S {
+                self.storage
+            }
+        }
+        impl<S: AsRef<[u8]>> View<S> {
+            $($ro)*
+
+            /// Run every field's validator over the whole buffer in one pass, stopping at (and
+            /// returning) the first failure.
+            pub fn validate(&self) -> Result<(), $crate::errors::ValidationError> {
+                let $sv = self.storage.as_ref();
+                $($validations)*
+                Ok(())
+            }
+
+            /// Reinterpret this view's storage as a view of a different, equally-sized layout.
+            pub fn reinterpret<To: $crate::layout_view::LayoutView<S>>(self) -> To {
+                $crate::layout_view::reinterpret(self)
+            }
+        }
+        impl<S: AsMut<[u8]>> View<S> {
+            $($rw)*
+        }
+    };
+
+    // ---- fixed-size raw byte array: `name: [u8; N]` ----
+    (@endian[$endian:ident] @offset[$offset:expr]
+     @items[$($items:item)*] @ro[$($ro:item)*] @rw[$($rw:item)*] @validations[$($validations:tt)*] @sv[$sv:ident]
+     @body[$name:ident : [u8; $n:literal] , $($rest:tt)*]) => {
+        $crate::paste::paste! {
+            $crate::_dl_fields!(
+                @endian[$endian]
+                @offset[($offset) + ($n)]
+                @items[$($items)*
+                    pub struct $name;
+                    impl $crate::fields::Field for $name {
+                        type HighLevelType = [u8; $n];
+                        const OFFSET: usize = $offset;
+                        const SIZE: usize = $n;
+                    }
+                    impl $crate::fields::FieldReadExt for $name {
+                        #[inline(always)]
+                        fn read(storage: &[u8]) -> [u8; $n] {
+                            storage[$offset..$offset + $n].try_into().unwrap()
+                        }
+                    }
+                    impl $crate::fields::FieldWriteExt for $name {
+                        #[inline(always)]
+                        fn write(storage: &mut [u8], v: [u8; $n]) {
+                            storage[$offset..$offset + $n].copy_from_slice(&v);
+                        }
+                    }
+                    impl $crate::errors::FieldValidator for $name {
+                        const NAME: &'static str = stringify!($name);
+                    }
+                ]
+                @ro[$($ro)*
+                    #[inline(always)]
+                    pub fn $name(&self) -> $crate::fields::primitive::view::FieldView<&[u8], $name> {
+                        $crate::fields::primitive::view::FieldView::new(self.storage.as_ref())
+                    }
+                ]
+                @rw[$($rw)*
+                    #[inline(always)]
+                    pub fn [<$name _mut>](&mut self) -> $crate::fields::primitive::view::FieldView<&mut [u8], $name> {
+                        $crate::fields::primitive::view::FieldView::new(self.storage.as_mut())
+                    }
+                ]
+                @validations[$($validations)* { <$name as $crate::errors::FieldValidator>::validate($sv)?; }]
+                @sv[$sv]
+                @body[$($rest)*]
+            );
+        }
+    };
+
+    // ---- nested layout field: `name: modname::NestedView` ----
+    (@endian[$endian:ident] @offset[$offset:expr]
+     @items[$($items:item)*] @ro[$($ro:item)*] @rw[$($rw:item)*] @validations[$($validations:tt)*] @sv[$sv:ident]
+     @body[$name:ident : $modname:ident :: NestedView , $($rest:tt)*]) => {
+        $crate::paste::paste! {
+            $crate::_dl_fields!(
+                @endian[$endian]
+                @offset[($offset) + ($modname::SIZE)]
+                @items[$($items)*]
+                @ro[$($ro)*
+                    #[inline(always)]
+                    pub fn $name(&self) -> $modname::View<&[u8]> {
+                        $modname::View::new($crate::fields::primitive::nested::nested_subslice(
+                            self.storage.as_ref(), stringify!($name), $offset, $modname::SIZE,
+                        ))
+                    }
+                ]
+                @rw[$($rw)*
+                    #[inline(always)]
+                    pub fn [<$name _mut>](&mut self) -> $modname::View<&mut [u8]> {
+                        $modname::View::new($crate::fields::primitive::nested::nested_subslice_mut(
+                            self.storage.as_mut(), stringify!($name), $offset, $modname::SIZE,
+                        ))
+                    }
+                ]
+                @validations[$($validations)* {
+                    if $offset + $modname::SIZE > $sv.len() {
+                        return Err($crate::errors::ValidationError::FieldTooShort {
+                            field: stringify!($name), offset: $offset, size: $modname::SIZE, storage_len: $sv.len(),
+                        });
+                    }
+                    $modname::View::new(&$sv[$offset..$offset + $modname::SIZE]).validate()?;
+                }]
+                @sv[$sv]
+                @body[$($rest)*]
+            );
+        }
+    };
+
+    // ---- length-prefixed variable-size field: switches to runtime-offset mode ----
+    (@endian[$endian:ident] @offset[$offset:expr]
+     @items[$($items:item)*] @ro[$($ro:item)*] @rw[$($rw:item)*] @validations[$($validations:tt)*] @sv[$sv:ident]
+     @body[$name:ident : [u8; $lenfield:ident] , $($rest:tt)*]) => {
+        $crate::_dl_runtime_fields!(
+            @endian[$endian]
+            @items[$($items)*]
+            @ro[$($ro)*]
+            @rw[$($rw)*]
+            @validations[$($validations)*]
+            @sv[$sv]
+            @prefix_offset[$offset]
+            @body[$name : [u8; $lenfield] , $($rest)*]
+        );
+    };
+
+    // ---- plain fixed field: `name: <bare primitive or NonZero* type ident>` ----
+    (@endian[$endian:ident] @offset[$offset:expr]
+     @items[$($items:item)*] @ro[$($ro:item)*] @rw[$($rw:item)*] @validations[$($validations:tt)*] @sv[$sv:ident]
+     @body[$name:ident : $t:ident , $($rest:tt)*]) => {
+        $crate::paste::paste! {
+            $crate::_dl_fields!(
+                @endian[$endian]
+                @offset[($offset) + (<$name as $crate::Field>::SIZE)]
+                @items[$($items)*
+                    pub struct $name;
+                ]
+                @ro[$($ro)*
+                    #[inline(always)]
+                    pub fn $name(&self) -> $crate::fields::primitive::view::FieldView<&[u8], $name> {
+                        $crate::fields::primitive::view::FieldView::new(self.storage.as_ref())
+                    }
+                ]
+                @rw[$($rw)*
+                    #[inline(always)]
+                    pub fn [<$name _mut>](&mut self) -> $crate::fields::primitive::view::FieldView<&mut [u8], $name> {
+                        $crate::fields::primitive::view::FieldView::new(self.storage.as_mut())
+                    }
+                ]
+                @validations[$($validations)* { <$name as $crate::errors::FieldValidator>::validate($sv)?; }]
+                @sv[$sv]
+                @body[$($rest)*]
+            );
+            $crate::_dl_field_dispatch!($endian, $t, $name, stringify!($name), $offset);
+        }
+    };
+}
+
+/// Emits the per-arm sub-layout modules, the `Variant<S>` enum and
+/// `View::variant()`/`variant_mut()`/`set_*()`/`validate()` for a `tag: T, variants { ... }`
+/// block. Split out of [`_dl_fields!`] because it needs its own token-munching over the arm list
+/// (to build the enum body) before it can close out the `View` impls, which [`_dl_fields!`]'s
+/// single linear accumulator doesn't have room for.
+///
+/// Arm names double as both the generated sub-module name and the `Variant` enum's variant name
+/// (e.g. arm `0 => A { ... }` becomes both `my_layout::A::View` and `my_layout::Variant::A`), so
+/// they're written capitalized like any other enum variant -- this sidesteps needing case
+/// conversion inside a declarative macro, which `macro_rules!` has no built-in support for. The
+/// same capitalized spelling is reused for the `set_A`/`set_B`/... setters below, which is why
+/// the generated module allows `non_snake_case`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _dl_emit_variants {
+    (@endian[$endian:ident] @tag[$tag:ident] @tagty[$tagty:ident] @variant_offset[$voff:expr]
+     @ro[$($ro:item)*] @rw[$($rw:item)*] @validations[$($validations:tt)*] @sv[$sv:ident]
+     @arms[$($disc:literal => $vname:ident { $($vfields:tt)* })+]) => {
+        $(
+            pub mod $vname {
+                #![allow(non_camel_case_types, non_snake_case, dead_code, unused_imports)]
+                use super::*;
+
+                $crate::_dl_fields!(
+                    @endian[$endian]
+                    @offset[$voff]
+                    @items[]
+                    @ro[]
+                    @rw[]
+                    @validations[]
+                    @sv[storage]
+                    @body[$($vfields)*]
+                );
+            }
+        )+
+
+        pub struct View<S> {
+            storage: S,
+        }
+        impl<S> View<S> {
+            #[inline(always)]
+            pub fn new(storage: S) -> Self {
+                Self { storage }
+            }
+            #[inline(always)]
+            pub fn into_storage(self) -> S {
+                self.storage
+            }
+        }
+
+        /// One sub-layout per `variants { ... }` arm, selected at runtime by the tag field.
+        pub enum Variant<S> {
+            $(
+                #[allow(missing_docs)]
+                $vname($vname::View<S>)
+            ),+
+        }
+
+        impl<S: AsRef<[u8]>> View<S> {
+            /// Read the tag field and return the matching arm's view, or
+            /// [`UnknownDiscriminantError`](crate::fields::primitive::discriminant::UnknownDiscriminantError)
+            /// if it holds no declared discriminant.
+            pub fn variant(&self) -> Result<Variant<&[u8]>, $crate::fields::primitive::discriminant::UnknownDiscriminantError> {
+                let discriminant = <$tag as $crate::fields::FieldReadExt>::read(self.storage.as_ref()) as u64;
+                match discriminant {
+                    $(
+                        $disc => Ok(Variant::$vname($vname::View::new(self.storage.as_ref()))),
+                    )+
+                    other => Err($crate::fields::primitive::discriminant::UnknownDiscriminantError {
+                        field: stringify!($tag),
+                        discriminant: other,
+                    }),
+                }
+            }
+
+            /// Run the tag field's validator, then the validator of whichever arm it selects.
+            pub fn validate(&self) -> Result<(), $crate::errors::ValidationError> {
+                let $sv = self.storage.as_ref();
+                $($validations)*
+                match self.variant()? {
+                    $(
+                        Variant::$vname(v) => v.validate(),
+                    )+
+                }
+            }
+
+            $($ro)*
+        }
+        impl<S: AsRef<[u8]> + AsMut<[u8]>> View<S> {
+            /// Read the tag field and return the matching arm's mutable view, or
+            /// [`UnknownDiscriminantError`](crate::fields::primitive::discriminant::UnknownDiscriminantError)
+            /// if it holds no declared discriminant.
+            pub fn variant_mut(&mut self) -> Result<Variant<&mut [u8]>, $crate::fields::primitive::discriminant::UnknownDiscriminantError> {
+                let discriminant = <$tag as $crate::fields::FieldReadExt>::read(self.storage.as_ref()) as u64;
+                match discriminant {
+                    $(
+                        $disc => Ok(Variant::$vname($vname::View::new(self.storage.as_mut()))),
+                    )+
+                    other => Err($crate::fields::primitive::discriminant::UnknownDiscriminantError {
+                        field: stringify!($tag),
+                        discriminant: other,
+                    }),
+                }
+            }
+
+            $crate::paste::paste! {
+                $(
+                    /// Write the
+                    #[doc = stringify!($disc)]
+                    /// discriminant into the tag field and return a mutable view of the
+                    #[doc = stringify!($vname)]
+                    /// variant, regardless of what the tag previously held.
+                    #[inline(always)]
+                    pub fn [<set_ $vname>](&mut self) -> $vname::View<&mut [u8]> {
+                        <$tag as $crate::fields::FieldWriteExt>::write(self.storage.as_mut(), $disc as $tagty);
+                        $vname::View::new(self.storage.as_mut())
+                    }
+                )+
+            }
+
+            $($rw)*
+        }
+    };
+}
+
+/// Generates the accessors for a length-prefixed field and everything that follows it, once
+/// [`_dl_fields!`] has switched into runtime-offset mode. Every accessor here returns
+/// `Result<T, SizeTooBigError>` -- the length-prefixed field itself and every fixed field after it
+/// alike -- rather than the infallible [`FieldView`](crate::fields::primitive::view::FieldView)
+/// shape plain fields use: none of these fields has a compile-time [`Field::OFFSET`], since it
+/// depends on a length read out of the buffer at runtime, so they can't implement
+/// [`Field`](crate::fields::Field) at all, and a consistent fallible-value return is the closest
+/// equivalent.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _dl_runtime_fields {
+    // the length-prefixed field itself
+    (@endian[$endian:ident] @items[$($items:item)*] @ro[$($ro:item)*] @rw[$($rw:item)*] @validations[$($validations:tt)*] @sv[$sv:ident]
+     @prefix_offset[$prefix_offset:expr]
+     @body[$name:ident : [u8; $lenfield:ident] , $($rest:tt)*]) => {
+        $crate::_dl_runtime_fields!(
+            @endian[$endian]
+            @items[$($items)*]
+            @ro[$($ro)*
+                #[inline(always)]
+                pub fn $name(&self) -> ::core::result::Result<&[u8], $crate::fields::primitive::runtime_offset::SizeTooBigError> {
+                    let $sv = self.storage.as_ref();
+                    let __bl_len = <$lenfield as $crate::fields::FieldReadExt>::read($sv) as usize;
+                    let end = $crate::fields::primitive::runtime_offset::runtime_offset_after_variable_field(
+                        $sv.len(), $prefix_offset, stringify!($name), __bl_len,
+                    )?;
+                    Ok(&$sv[$prefix_offset..end])
+                }
+            ]
+            @rw[$($rw)*
+                $crate::paste::paste! {
+                    #[inline(always)]
+                    pub fn [<$name _mut>](&mut self) -> ::core::result::Result<&mut [u8], $crate::fields::primitive::runtime_offset::SizeTooBigError> {
+                        let __bl_len = <$lenfield as $crate::fields::FieldReadExt>::read(self.storage.as_ref()) as usize;
+                        let storage_len = self.storage.as_ref().len();
+                        let end = $crate::fields::primitive::runtime_offset::runtime_offset_after_variable_field(
+                            storage_len, $prefix_offset, stringify!($name), __bl_len,
+                        )?;
+                        Ok(&mut self.storage.as_mut()[$prefix_offset..end])
+                    }
+                }
+            ]
+            @validations[$($validations)* {
+                let __bl_len = <$lenfield as $crate::fields::FieldReadExt>::read($sv) as usize;
+                $crate::fields::primitive::runtime_offset::runtime_offset_after_variable_field(
+                    $sv.len(), $prefix_offset, stringify!($name), __bl_len,
+                )?;
+            }]
+            @sv[$sv]
+            @base[{
+                let __bl_len = <$lenfield as $crate::fields::FieldReadExt>::read($sv) as usize;
+                $crate::fields::primitive::runtime_offset::runtime_offset_after_variable_field(
+                    $sv.len(), $prefix_offset, stringify!($name), __bl_len,
+                )?
+            }]
+            @extra_offset[0usize]
+            @body[$($rest)*]
+        );
+    };
+
+    // a plain fixed field trailing the variable-length one
+    (@endian[$endian:ident] @items[$($items:item)*] @ro[$($ro:item)*] @rw[$($rw:item)*] @validations[$($validations:tt)*] @sv[$sv:ident]
+     @base[$base:block] @extra_offset[$extra:expr]
+     @body[$name:ident : $t:ident , $($rest:tt)*]) => {
+        $crate::_dl_runtime_fields!(
+            @endian[$endian]
+            @items[$($items)*]
+            @ro[$($ro)*
+                #[inline(always)]
+                pub fn $name(&self) -> ::core::result::Result<$t, $crate::fields::primitive::runtime_offset::SizeTooBigError> {
+                    let $sv = self.storage.as_ref();
+                    let start = $base + ($extra);
+                    let size = <$t as $crate::fields::primitive::Primitive>::SIZE;
+                    if start + size > $sv.len() {
+                        return Err($crate::fields::primitive::runtime_offset::SizeTooBigError {
+                            field: stringify!($name),
+                            declared_len: size,
+                            available_len: $sv.len().saturating_sub(start),
+                        });
+                    }
+                    Ok($crate::_dl_runtime_read!($endian, $t, &$sv[start..start + size]))
+                }
+            ]
+            @rw[$($rw)*
+                $crate::paste::paste! {
+                    #[inline(always)]
+                    pub fn [<$name _mut>](&mut self, value: $t) -> ::core::result::Result<(), $crate::fields::primitive::runtime_offset::SizeTooBigError> {
+                        let start = {
+                            let $sv = self.storage.as_ref();
+                            ($base) + ($extra)
+                        };
+                        let size = <$t as $crate::fields::primitive::Primitive>::SIZE;
+                        let storage_len = self.storage.as_ref().len();
+                        if start + size > storage_len {
+                            return Err($crate::fields::primitive::runtime_offset::SizeTooBigError {
+                                field: stringify!($name),
+                                declared_len: size,
+                                available_len: storage_len.saturating_sub(start),
+                            });
+                        }
+                        $crate::_dl_runtime_write!($endian, $t, value, &mut self.storage.as_mut()[start..start + size]);
+                        Ok(())
+                    }
+                }
+            ]
+            @validations[$($validations)* {
+                let start = $base + ($extra);
+                let size = <$t as $crate::fields::primitive::Primitive>::SIZE;
+                if start + size > $sv.len() {
+                    return Err($crate::errors::ValidationError::SizeTooBig($crate::fields::primitive::runtime_offset::SizeTooBigError {
+                        field: stringify!($name),
+                        declared_len: size,
+                        available_len: $sv.len().saturating_sub(start),
+                    }));
+                }
+            }]
+            @sv[$sv]
+            @base[$base]
+            @extra_offset[($extra) + (<$t as $crate::fields::primitive::Primitive>::SIZE)]
+            @body[$($rest)*]
+        );
+    };
+
+    // terminal
+    (@endian[$endian:ident] @items[$($items:item)*] @ro[$($ro:item)*] @rw[$($rw:item)*] @validations[$($validations:tt)*] @sv[$sv:ident]
+     @base[$base:block] @extra_offset[$extra:expr]
+     @body[]) => {
+        pub struct View<S> {
+            storage: S,
+        }
+        impl<S> View<S> {
+            #[inline(always)]
+            pub fn new(storage: S) -> Self {
+                Self { storage }
+            }
+            #[inline(always)]
+            pub fn into_storage(self) -> S {
+                self.storage
+            }
+        }
+        $($items)*
+        impl<S: AsRef<[u8]>> View<S> {
+            $($ro)*
+
+            /// Run every fixed-prefix field's validator, then check that the length-prefixed
+            /// field's declared length actually fits in the remaining storage.
+            pub fn validate(&self) -> Result<(), $crate::errors::ValidationError> {
+                let $sv = self.storage.as_ref();
+                $($validations)*
+                Ok(())
+            }
+        }
+        impl<S: AsRef<[u8]> + AsMut<[u8]>> View<S> {
+            $($rw)*
+        }
+    };
+}
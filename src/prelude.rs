@@ -0,0 +1,13 @@
+//! Re-exports everything a typical user of [`define_layout!`](crate::define_layout!) needs.
+//!
+//! ```
+//! use binary_layout::prelude::*;
+//! ```
+
+pub use crate::define_layout;
+pub use crate::errors::{NonZeroIsZeroError, ValidationError};
+pub use crate::fields::primitive::discriminant::UnknownDiscriminantError;
+pub use crate::fields::primitive::runtime_offset::SizeTooBigError;
+pub use crate::fields::primitive::view::FieldView;
+pub use crate::fields::{BigEndian, LittleEndian};
+pub use crate::layout_view::{reinterpret, LayoutView};
@@ -0,0 +1,60 @@
+use binary_layout::prelude::*;
+
+define_layout!(tagged, LittleEndian, {
+    tag: u8,
+    variants {
+        0 => A { value: u32, },
+        1 => B { value: u8, },
+    }
+});
+
+#[test]
+fn variant_reads_the_matching_arm() {
+    let mut storage = [0u8; 5];
+    let mut view = tagged::View::new(&mut storage[..]);
+    view.tag_mut().write(0);
+    view.set_A().value_mut().write(42);
+
+    let view = tagged::View::new(&storage[..]);
+    match view.variant().unwrap() {
+        tagged::Variant::A(a) => assert_eq!(42, a.value().read()),
+        tagged::Variant::B(_) => panic!("expected variant A"),
+    }
+}
+
+#[test]
+fn variant_rejects_unknown_discriminant() {
+    let mut storage = [0u8; 5];
+    let mut view = tagged::View::new(&mut storage[..]);
+    view.tag_mut().write(99);
+
+    let view = tagged::View::new(&storage[..]);
+    assert!(view.variant().is_err());
+}
+
+#[test]
+fn set_variant_writes_the_tag_and_returns_a_mutable_sub_view() {
+    let mut storage = [0u8; 5];
+    let mut view = tagged::View::new(&mut storage[..]);
+    view.set_B().value_mut().write(7);
+
+    assert_eq!(1, view.tag().read());
+    match view.variant_mut().unwrap() {
+        tagged::Variant::A(_) => panic!("expected variant B"),
+        tagged::Variant::B(b) => assert_eq!(7, b.value().read()),
+    }
+}
+
+#[test]
+fn set_variant_overwrites_a_previously_selected_variant() {
+    let mut storage = [0u8; 5];
+    let mut view = tagged::View::new(&mut storage[..]);
+    view.set_A().value_mut().write(42);
+    view.set_B().value_mut().write(7);
+
+    assert_eq!(1, view.tag().read());
+    match view.variant().unwrap() {
+        tagged::Variant::A(_) => panic!("expected variant B"),
+        tagged::Variant::B(b) => assert_eq!(7, b.value().read()),
+    }
+}
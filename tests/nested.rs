@@ -0,0 +1,30 @@
+use binary_layout::prelude::*;
+
+define_layout!(header, LittleEndian, {
+    version: u32,
+});
+
+define_layout!(with_nested, LittleEndian, {
+    head: header::NestedView,
+    checksum: u32,
+});
+
+#[test]
+fn nested_field_projects_into_sub_layout() {
+    let mut storage = [0u8; 8];
+    let mut view = with_nested::View::new(&mut storage[..]);
+    view.head_mut().version_mut().write(7);
+    view.checksum_mut().write(42);
+
+    let view = with_nested::View::new(&storage[..]);
+    assert_eq!(7, view.head().version().read());
+    assert_eq!(42, view.checksum().read());
+}
+
+#[test]
+#[should_panic(expected = "Tried to view nested field")]
+fn nested_field_panics_with_friendly_message_on_short_storage() {
+    let storage = [0u8; 2];
+    let view = with_nested::View::new(&storage[..]);
+    let _ = view.head();
+}
@@ -0,0 +1,38 @@
+use binary_layout::prelude::*;
+
+define_layout!(with_blob, LittleEndian, {
+    blob: [u8; 4],
+});
+
+#[test]
+fn copy_from_copies_raw_bytes() {
+    let src_storage = [1, 2, 3, 4];
+    let mut dst_storage = [0u8; 4];
+    let src_view = with_blob::View::new(&src_storage[..]);
+    let mut dst_view = with_blob::View::new(&mut dst_storage[..]);
+    dst_view.blob_mut().copy_from(&src_view.blob());
+    assert_eq!(src_storage, dst_storage);
+}
+
+define_layout!(header_a, LittleEndian, { version: u32, flags: u32, });
+define_layout!(header_b, LittleEndian, { version: u32, other_flags: u32, });
+
+#[test]
+fn reinterpret_views_same_storage_as_a_different_layout() {
+    let mut storage = [0u8; 8];
+    let view = header_a::View::new(&mut storage[..]);
+    let mut view: header_b::View<&mut [u8]> = view.reinterpret();
+    view.other_flags_mut().write(7);
+    assert_eq!(7, header_b::View::new(&storage[..]).other_flags().read());
+}
+
+#[test]
+#[should_panic(expected = "Cannot reinterpret")]
+fn reinterpret_panics_on_size_mismatch() {
+    define_layout!(small, LittleEndian, { a: u32, });
+    define_layout!(big, LittleEndian, { a: u32, b: u32, });
+
+    let storage = [0u8; 4];
+    let view = small::View::new(&storage[..]);
+    let _: big::View<&[u8]> = view.reinterpret();
+}
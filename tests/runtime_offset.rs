@@ -0,0 +1,46 @@
+use binary_layout::prelude::*;
+
+define_layout!(variable, LittleEndian, {
+    len: u16,
+    payload: [u8; len],
+    checksum: u32,
+});
+
+#[test]
+fn length_prefixed_field_reads_declared_bytes() {
+    let mut storage = [0u8; 2 + 3 + 4];
+    let mut view = variable::View::new(&mut storage[..]);
+    view.len_mut().write(3);
+    view.payload_mut().unwrap().copy_from_slice(&[9, 8, 7]);
+    view.checksum_mut(42).unwrap();
+
+    let view = variable::View::new(&storage[..]);
+    assert_eq!(&[9, 8, 7], view.payload().unwrap());
+    assert_eq!(42, view.checksum().unwrap());
+}
+
+#[test]
+fn length_prefixed_field_reports_when_it_overruns_storage() {
+    let mut storage = [0u8; 2];
+    let mut view = variable::View::new(&mut storage[..]);
+    view.len_mut().write(100);
+
+    let view = variable::View::new(&storage[..]);
+    assert!(view.payload().is_err());
+}
+
+#[test]
+fn payload_mut_reports_when_it_overruns_storage() {
+    let mut storage = [0u8; 2];
+    let mut view = variable::View::new(&mut storage[..]);
+    view.len_mut().write(100);
+    assert!(view.payload_mut().is_err());
+}
+
+#[test]
+fn checksum_mut_reports_when_it_overruns_storage() {
+    let mut storage = [0u8; 2 + 3];
+    let mut view = variable::View::new(&mut storage[..]);
+    view.len_mut().write(3);
+    assert!(view.checksum_mut(42).is_err());
+}
@@ -0,0 +1,76 @@
+use binary_layout::prelude::*;
+
+define_layout!(flat, LittleEndian, {
+    field_one: u16,
+    field_two: NonZeroU32,
+});
+
+#[test]
+fn flat_validate_rejects_short_storage() {
+    let storage = [0u8; 4];
+    let view = flat::View::new(&storage[..]);
+    assert!(view.validate().is_err());
+}
+
+#[test]
+fn flat_validate_rejects_zero_nonzero_field() {
+    let storage = [0u8; 6];
+    let view = flat::View::new(&storage[..]);
+    assert!(matches!(
+        view.validate(),
+        Err(ValidationError::NonZeroIsZero { field: "field_two" })
+    ));
+}
+
+define_layout!(tagged, LittleEndian, {
+    tag: u8,
+    variants {
+        0 => A { value: u32, },
+        1 => B { value: u8, },
+    }
+});
+
+#[test]
+fn variant_validate_checks_tag_then_selected_arm() {
+    let mut storage = [0u8; 5];
+    let mut view = tagged::View::new(&mut storage[..]);
+    view.tag_mut().write(0);
+    let view = tagged::View::new(&storage[..]);
+    assert!(view.validate().is_ok());
+}
+
+#[test]
+fn variant_validate_rejects_unknown_discriminant() {
+    let mut storage = [0u8; 5];
+    let mut view = tagged::View::new(&mut storage[..]);
+    view.tag_mut().write(99);
+    let view = tagged::View::new(&storage[..]);
+    assert!(view.validate().is_err());
+}
+
+define_layout!(variable, LittleEndian, {
+    len: u16,
+    payload: [u8; len],
+    checksum: u32,
+});
+
+#[test]
+fn runtime_offset_validate_rejects_overrunning_length() {
+    let mut storage = [0u8; 2];
+    let mut view = variable::View::new(&mut storage[..]);
+    view.len_mut().write(100);
+    let view = variable::View::new(&storage[..]);
+    assert!(view.validate().is_err());
+}
+
+#[test]
+fn runtime_offset_validate_rejects_truncated_trailing_field() {
+    let mut storage = [0u8; 2 + 3];
+    let mut view = variable::View::new(&mut storage[..]);
+    view.len_mut().write(3);
+    let view = variable::View::new(&storage[..]);
+    assert!(matches!(
+        view.validate(),
+        Err(ValidationError::SizeTooBig(SizeTooBigError { field: "checksum", .. }))
+    ));
+}